@@ -17,16 +17,17 @@ from pathlib import Path
 # Make sure tyr package is importable
 sys.path.insert(0, str(Path(__file__).parent.parent))
 
-from tyr.core.config import get_config
+from tyr.core.config import HttpConfig, get_config
 from tyr.core.logging import get_logger, setup_logging
 from tyr.feeds.kalshi_rest import KalshiRestFeed
 from tyr.feeds.polymarket_rest import PolymarketRestClient, parse_market_info
+from tyr.utils.console import configure_console, print_bootstrap_summary
 
 logger = get_logger(__name__)
 
 
-async def bootstrap_polymarket(cache_dir: Path, page_size: int) -> int:
-    client = PolymarketRestClient(page_size=page_size)
+async def bootstrap_polymarket(cache_dir: Path, page_size: int, http: HttpConfig) -> int:
+    client = PolymarketRestClient(page_size=page_size, http=http)
     try:
         logger.info("Fetching Polymarket markets...")
         markets = await client.get_all_markets(active_only=True)
@@ -39,12 +40,12 @@ async def bootstrap_polymarket(cache_dir: Path, page_size: int) -> int:
         await client.close()
 
 
-async def bootstrap_kalshi(cache_dir: Path) -> int:
+async def bootstrap_kalshi(cache_dir: Path, http: HttpConfig) -> int:
     # KalshiRestFeed uses an httpx client internally
     import asyncio
 
     queue: asyncio.Queue = asyncio.Queue()
-    feed = KalshiRestFeed(queue)
+    feed = KalshiRestFeed(queue, http=http)
     try:
         logger.info("Fetching Kalshi markets...")
         markets = await feed.get_all_markets()
@@ -60,17 +61,19 @@ async def bootstrap_kalshi(cache_dir: Path) -> int:
 async def main() -> None:
     setup_logging("INFO")
     config = get_config()
+    configure_console(no_color=config.console_no_color, ascii_only=config.console_ascii)
     cache_dir = Path(config.market_cache_dir)
     cache_dir.mkdir(parents=True, exist_ok=True)
 
     poly_count, kalshi_count = await asyncio.gather(
-        bootstrap_polymarket(cache_dir, config.polymarket_page_size),
-        bootstrap_kalshi(cache_dir),
+        bootstrap_polymarket(cache_dir, config.polymarket_page_size, config.http),
+        bootstrap_kalshi(cache_dir, config.http),
     )
 
-    print(f"\nBootstrap complete:")
-    print(f"  Polymarket: {poly_count} markets → {cache_dir / 'polymarket_markets.json'}")
-    print(f"  Kalshi:     {kalshi_count} markets → {cache_dir / 'kalshi_markets.json'}")
+    print_bootstrap_summary(
+        poly_count, cache_dir / "polymarket_markets.json",
+        kalshi_count, cache_dir / "kalshi_markets.json",
+    )
 
 
 if __name__ == "__main__":
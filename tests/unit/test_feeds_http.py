@@ -0,0 +1,171 @@
+from __future__ import annotations
+
+import httpx
+import pytest
+
+from tyr.core.exceptions import (
+    FeedError,
+    InvalidResponseError,
+    ProviderUnavailableError,
+    RateLimitedError,
+)
+from tyr.feeds.http import Cassette, RecordingTransport, ReplayTransport, get_json
+
+BASE = "https://api.test"
+
+
+def _numbered_upstream() -> httpx.MockTransport:
+    """Upstream that numbers its responses so replay order is observable."""
+    calls = {"n": 0}
+
+    def handler(request: httpx.Request) -> httpx.Response:
+        calls["n"] += 1
+        return httpx.Response(
+            200 if request.url.path == "/markets" else 404,
+            headers={"x-call": str(calls["n"])},
+            json={"path": request.url.path, "call": calls["n"]},
+        )
+
+    return httpx.MockTransport(handler)
+
+
+async def _record(path) -> None:
+    transport = RecordingTransport(Cassette.create(path), inner=_numbered_upstream())
+    async with httpx.AsyncClient(base_url=BASE, transport=transport) as client:
+        await client.get("/markets", params={"limit": 10})
+        await client.get("/markets", params={"limit": 10})
+        await client.get("/missing")
+
+
+async def test_record_writes_one_ndjson_line_per_exchange(tmp_path):
+    path = tmp_path / "cassette.ndjson"
+    await _record(path)
+
+    lines = path.read_text().splitlines()
+    assert len(lines) == 3
+    assert Cassette(path).load()[0]["request"] == f"GET {BASE}/markets?limit=10"
+
+
+async def test_replay_returns_recorded_status_headers_and_body(tmp_path):
+    path = tmp_path / "cassette.ndjson"
+    await _record(path)
+
+    transport = ReplayTransport(Cassette(path).load())
+    async with httpx.AsyncClient(base_url=BASE, transport=transport) as client:
+        first = await client.get("/markets", params={"limit": 10})
+        missing = await client.get("/missing")
+
+    assert first.status_code == 200
+    assert first.headers["x-call"] == "1"
+    assert first.headers["content-type"] == "application/json"
+    assert first.json() == {"path": "/markets", "call": 1}
+    assert missing.status_code == 404
+    assert missing.json() == {"path": "/missing", "call": 3}
+
+
+async def test_replay_repeats_last_response_once_exhausted(tmp_path):
+    path = tmp_path / "cassette.ndjson"
+    await _record(path)
+
+    transport = ReplayTransport(Cassette(path).load())
+    async with httpx.AsyncClient(base_url=BASE, transport=transport) as client:
+        calls = [
+            (await client.get("/markets", params={"limit": 10})).json()["call"]
+            for _ in range(4)
+        ]
+
+    assert calls == [1, 2, 2, 2]
+
+
+async def test_replay_raises_for_unrecorded_request(tmp_path):
+    path = tmp_path / "cassette.ndjson"
+    await _record(path)
+
+    transport = ReplayTransport(Cassette(path).load())
+    async with httpx.AsyncClient(base_url=BASE, transport=transport) as client:
+        with pytest.raises(FeedError, match="No recorded response for GET"):
+            await client.get("/markets", params={"limit": 99})
+
+
+async def test_record_redacts_credential_headers(tmp_path):
+    path = tmp_path / "cassette.ndjson"
+    upstream = httpx.MockTransport(lambda request: httpx.Response(
+        200, headers={"set-cookie": "session=s3cret", "x-api-key": "k3y", "x-call": "1"}, json={},
+    ))
+    transport = RecordingTransport(Cassette.create(path), inner=upstream)
+    async with httpx.AsyncClient(base_url=BASE, transport=transport) as client:
+        await client.get("/markets")
+
+    assert "s3cret" not in path.read_text()
+    assert "k3y" not in path.read_text()
+    headers = Cassette(path).load()[0]["headers"]
+    assert headers["set-cookie"] == "<redacted>"
+    assert headers["x-api-key"] == "<redacted>"
+    assert headers["x-call"] == "1"
+
+
+def test_load_rejects_missing_and_corrupt_cassettes(tmp_path):
+    with pytest.raises(FeedError, match="not found"):
+        Cassette(tmp_path / "absent.ndjson").load()
+
+    path = tmp_path / "corrupt.ndjson"
+    path.write_text('{"request": "GET /a", "status": 200, "headers": {}, "body": ""}\n{"truncat')
+    with pytest.raises(FeedError, match="line 2"):
+        Cassette(path).load()
+
+
+def _client_returning(response: httpx.Response) -> httpx.AsyncClient:
+    return httpx.AsyncClient(base_url=BASE, transport=httpx.MockTransport(lambda request: response))
+
+
+@pytest.mark.parametrize(
+    ("headers", "retry_after"),
+    [({"Retry-After": "7"}, 7.0), ({}, None), ({"Retry-After": "soon"}, None)],
+)
+async def test_get_json_maps_429_to_rate_limited(headers, retry_after):
+    async with _client_returning(httpx.Response(429, headers=headers)) as client:
+        with pytest.raises(RateLimitedError) as excinfo:
+            await get_json(client, "/markets")
+    assert excinfo.value.retry_after == retry_after
+
+
+async def test_get_json_maps_5xx_to_provider_unavailable():
+    async with _client_returning(httpx.Response(503)) as client:
+        with pytest.raises(ProviderUnavailableError, match="HTTP 503"):
+            await get_json(client, "/markets")
+
+
+async def test_get_json_maps_transport_failure_to_provider_unavailable():
+    def handler(request: httpx.Request) -> httpx.Response:
+        raise httpx.ConnectError("refused", request=request)
+
+    async with httpx.AsyncClient(base_url=BASE, transport=httpx.MockTransport(handler)) as client:
+        with pytest.raises(ProviderUnavailableError, match="refused"):
+            await get_json(client, "/markets")
+
+
+async def test_get_json_maps_other_4xx_to_plain_feed_error():
+    async with _client_returning(httpx.Response(404)) as client:
+        with pytest.raises(FeedError, match="HTTP 404") as excinfo:
+            await get_json(client, "/markets")
+    assert type(excinfo.value) is FeedError
+
+
+async def test_get_json_rejects_invalid_json_with_capped_raw_text():
+    body = "<html>" + "x" * 10_000
+    async with _client_returning(httpx.Response(200, text=body)) as client:
+        with pytest.raises(InvalidResponseError, match="invalid JSON") as excinfo:
+            await get_json(client, "/markets")
+    assert excinfo.value.raw == body[:2000]
+
+
+@pytest.mark.parametrize("payload", [[{"ticker": "A"}], "ok", 42])
+async def test_get_json_rejects_non_object_json(payload):
+    async with _client_returning(httpx.Response(200, json=payload)) as client:
+        with pytest.raises(InvalidResponseError, match="expected an object"):
+            await get_json(client, "/markets")
+
+
+async def test_get_json_returns_object_payload():
+    async with _client_returning(httpx.Response(200, json={"markets": []})) as client:
+        assert await get_json(client, "/markets") == {"markets": []}
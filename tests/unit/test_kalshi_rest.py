@@ -0,0 +1,85 @@
+from __future__ import annotations
+
+import asyncio
+
+import httpx
+import pytest
+
+from tyr.core.exceptions import ProviderUnavailableError, RateLimitedError
+from tyr.feeds.kalshi_rest import KALSHI_BASE, KalshiRestFeed
+
+
+async def _feed_with(handler) -> KalshiRestFeed:
+    feed = KalshiRestFeed(asyncio.Queue())
+    await feed._client.aclose()
+    feed._client = httpx.AsyncClient(base_url=KALSHI_BASE, transport=httpx.MockTransport(handler))
+    return feed
+
+
+async def test_get_all_markets_retries_rate_limited_page_on_same_cursor():
+    responses = iter([
+        httpx.Response(200, json={"markets": [{"ticker": "A"}], "cursor": "c1"}),
+        httpx.Response(429, headers={"Retry-After": "0"}),
+        httpx.Response(200, json={"markets": [{"ticker": "B"}], "cursor": ""}),
+    ])
+    cursors: list[str | None] = []
+
+    def handler(request: httpx.Request) -> httpx.Response:
+        cursors.append(request.url.params.get("cursor"))
+        return next(responses)
+
+    feed = await _feed_with(handler)
+    try:
+        markets = await feed.get_all_markets()
+    finally:
+        await feed.stop()
+
+    assert [m["ticker"] for m in markets] == ["A", "B"]
+    assert cursors == [None, "c1", "c1"]
+
+
+async def test_get_all_markets_raises_when_rate_limit_persists():
+    feed = await _feed_with(lambda request: httpx.Response(429, headers={"Retry-After": "0"}))
+    try:
+        with pytest.raises(RateLimitedError):
+            await feed.get_all_markets()
+    finally:
+        await feed.stop()
+
+
+async def test_get_all_markets_retries_server_error_mid_pagination():
+    responses = iter([
+        httpx.Response(200, json={"markets": [{"ticker": "A"}], "cursor": "c1"}),
+        httpx.Response(503),
+        httpx.Response(200, json={"markets": [{"ticker": "B"}], "cursor": ""}),
+    ])
+    cursors: list[str | None] = []
+
+    def handler(request: httpx.Request) -> httpx.Response:
+        cursors.append(request.url.params.get("cursor"))
+        return next(responses)
+
+    feed = await _feed_with(handler)
+    feed.poll_interval = 0.0
+    try:
+        markets = await feed.get_all_markets()
+    finally:
+        await feed.stop()
+
+    assert [m["ticker"] for m in markets] == ["A", "B"]
+    assert cursors == [None, "c1", "c1"]
+
+
+async def test_get_all_markets_raises_instead_of_truncating_on_persistent_error():
+    responses = iter([httpx.Response(200, json={"markets": [{"ticker": "A"}], "cursor": "c1"})])
+
+    def handler(request: httpx.Request) -> httpx.Response:
+        return next(responses, httpx.Response(503))
+
+    feed = await _feed_with(handler)
+    feed.poll_interval = 0.0
+    try:
+        with pytest.raises(ProviderUnavailableError):
+            await feed.get_all_markets()
+    finally:
+        await feed.stop()
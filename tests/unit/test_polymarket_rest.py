@@ -0,0 +1,29 @@
+from __future__ import annotations
+
+import httpx
+
+from tyr.feeds.polymarket_rest import CLOB_BASE, PolymarketRestClient
+
+
+async def test_get_all_markets_retries_rate_limited_page():
+    responses = iter([
+        httpx.Response(200, json={"data": [{"condition_id": "a"}], "next_cursor": "c1"}),
+        httpx.Response(429, headers={"Retry-After": "0"}),
+        httpx.Response(200, json={"data": [{"condition_id": "b"}], "next_cursor": "LTE="}),
+    ])
+    cursors: list[str | None] = []
+
+    def handler(request: httpx.Request) -> httpx.Response:
+        cursors.append(request.url.params.get("next_cursor"))
+        return next(responses)
+
+    client = PolymarketRestClient(page_size=1)
+    await client.close()
+    client._client = httpx.AsyncClient(base_url=CLOB_BASE, transport=httpx.MockTransport(handler))
+    try:
+        markets = await client.get_all_markets()
+    finally:
+        await client.close()
+
+    assert [m["condition_id"] for m in markets] == ["a", "b"]
+    assert cursors == [None, "c1", "c1"]
@@ -45,17 +45,39 @@ class FeeConfig(BaseSettings):
     model_config = SettingsConfigDict(env_prefix="FEES__", extra="ignore")
 
 
+class HttpConfig(BaseSettings):
+    max_connections: int = 20
+    max_keepalive_connections: int = 10
+    keepalive_expiry_secs: float = 30.0
+    timeout_secs: float = 15.0
+    http2: bool = False                  # needs `pip install tyr[http2]`
+
+    # Cassette paths for record/replay of REST traffic (see tyr/feeds/http.py)
+    record_path: Optional[str] = Field(default=None, validation_alias="TYR_RECORD")
+    replay_path: Optional[str] = Field(default=None, validation_alias="TYR_REPLAY")
+
+    model_config = SettingsConfigDict(
+        env_prefix="HTTP__",
+        env_file=".env",
+        env_file_encoding="utf-8",
+        extra="ignore",
+        populate_by_name=True,
+    )
+
+
 class TyrConfig(BaseSettings):
     environment: str = "development"
     log_level: str = "INFO"
     log_file: str = "data/logs/tyr.log"
     db_path: str = "data/db/tyr.db"
+    db_url: Optional[str] = None         # e.g. postgresql+asyncpg://... — overrides db_path
     market_cache_dir: str = "data/market_cache"
 
     polymarket: PolymarketCreds = Field(default_factory=PolymarketCreds)
     kalshi: KalshiCreds = Field(default_factory=KalshiCreds)
     thresholds: ArbitrageThresholds = Field(default_factory=ArbitrageThresholds)
     fees: FeeConfig = Field(default_factory=FeeConfig)
+    http: HttpConfig = Field(default_factory=HttpConfig)
 
     enable_spread_scanner: bool = True
     enable_cross_market_scanner: bool = True
@@ -66,6 +88,9 @@ class TyrConfig(BaseSettings):
     kalshi_poll_interval: float = 5.0
     polymarket_page_size: int = 500
 
+    console_no_color: bool = False       # NO_COLOR env var is also honoured
+    console_ascii: bool = False          # force ASCII glyphs (auto-enabled if stdout is not Unicode)
+
     model_config = SettingsConfigDict(
         env_file=".env",
         env_file_encoding="utf-8",
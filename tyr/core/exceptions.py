@@ -10,6 +10,26 @@ class FeedError(TyrError):
     """Data feed connection or parsing error."""
 
 
+class ProviderUnavailableError(FeedError):
+    """Upstream API unreachable or returning server errors."""
+
+
+class RateLimitedError(FeedError):
+    """Upstream API rejected the request with HTTP 429."""
+
+    def __init__(self, message: str, retry_after: float | None = None):
+        super().__init__(message)
+        self.retry_after = retry_after
+
+
+class InvalidResponseError(FeedError):
+    """Upstream API returned a payload that could not be parsed."""
+
+    def __init__(self, message: str, raw: str = ""):
+        super().__init__(message)
+        self.raw = raw
+
+
 class ExecutionError(TyrError):
     """Order placement or execution failure."""
 
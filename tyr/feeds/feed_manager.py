@@ -34,6 +34,7 @@ class FeedManager:
             self.queue,
             tickers=kalshi_tickers,
             poll_interval=self.config.kalshi_poll_interval,
+            http=self.config.http,
         )
 
         self._tasks = [
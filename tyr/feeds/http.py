@@ -0,0 +1,263 @@
+"""Shared httpx client construction for the REST feeds.
+
+Set TYR_RECORD=path (HttpConfig.record_path) to capture every request/response
+pair to an NDJSON cassette, or TYR_REPLAY=path (HttpConfig.replay_path) to
+serve responses from a previously recorded cassette without touching the
+network. Both can go in .env. Useful for reproducing bug reports and for
+running the feeds deterministically in CI.
+"""
+from __future__ import annotations
+
+import asyncio
+import json
+import threading
+from pathlib import Path
+from typing import Optional
+
+import httpx
+
+from tyr.core.config import HttpConfig
+from tyr.core.exceptions import (
+    FeedError,
+    InvalidResponseError,
+    ProviderUnavailableError,
+    RateLimitedError,
+)
+from tyr.core.logging import get_logger
+
+logger = get_logger(__name__)
+
+# Bodies are stored decoded, so transfer-level headers no longer apply on replay
+_DROP_HEADERS = {"content-encoding", "content-length", "transfer-encoding"}
+
+# Cassettes get attached to bug reports, so credential-bearing values are masked
+_REDACT_HEADERS = {"set-cookie", "cookie", "authorization", "proxy-authorization", "x-api-key"}
+_REDACTED = "<redacted>"
+
+# Cap on the response text kept on InvalidResponseError (it ends up in logs)
+_RAW_PREVIEW_CHARS = 2000
+
+# Transient-failure retries for paginated bootstrap fetches
+_MAX_RETRIES = 5
+_RETRY_DELAY_SECS = 5.0
+
+# One cassette per path so several clients in a process record into the same file
+_cassettes: dict[Path, "Cassette"] = {}
+
+
+def _request_key(request: httpx.Request) -> str:
+    return f"{request.method} {request.url}"
+
+
+class Cassette:
+    """Request/response pairs persisted as NDJSON, one exchange per line.
+
+    Recording appends a line per exchange and keeps nothing in memory, so long
+    polling sessions stay bounded and a crash loses at most the last line.
+    """
+
+    def __init__(self, path: Path):
+        self.path = path
+        self._lock = threading.Lock()
+
+    @classmethod
+    def create(cls, path: Path) -> "Cassette":
+        """Start a fresh recording at `path`, truncating any previous session."""
+        path.parent.mkdir(parents=True, exist_ok=True)
+        path.write_text("")
+        return cls(path)
+
+    def load(self) -> list[dict]:
+        if not self.path.exists():
+            raise FeedError(f"Replay cassette not found: {self.path}")
+
+        entries: list[dict] = []
+        with self.path.open(encoding="utf-8") as fh:
+            for lineno, line in enumerate(fh, start=1):
+                if not line.strip():
+                    continue
+                try:
+                    entries.append(json.loads(line))
+                except json.JSONDecodeError as exc:
+                    raise FeedError(f"Corrupt cassette {self.path} at line {lineno}: {exc}") from exc
+        return entries
+
+    def append(self, request: httpx.Request, response: httpx.Response) -> None:
+        line = json.dumps({
+            "request": _request_key(request),
+            "status": response.status_code,
+            "headers": {
+                k: _REDACTED if k.lower() in _REDACT_HEADERS else v
+                for k, v in response.headers.items()
+                if k.lower() not in _DROP_HEADERS
+            },
+            "body": response.content.decode("utf-8", errors="replace"),
+        })
+        # Called from worker threads; serialise so lines never interleave
+        with self._lock, self.path.open("a", encoding="utf-8") as fh:
+            fh.write(line + "\n")
+
+
+class RecordingTransport(httpx.AsyncBaseTransport):
+    """Forwards requests to the network and appends each exchange to a cassette."""
+
+    def __init__(self, cassette: Cassette, inner: Optional[httpx.AsyncBaseTransport] = None):
+        self._cassette = cassette
+        self._inner = inner or httpx.AsyncHTTPTransport()
+
+    async def handle_async_request(self, request: httpx.Request) -> httpx.Response:
+        raw = await self._inner.handle_async_request(request)
+        try:
+            body = await raw.aread()
+        finally:
+            await raw.aclose()
+
+        response = httpx.Response(
+            status_code=raw.status_code,
+            headers=[(k, v) for k, v in raw.headers.items() if k.lower() not in _DROP_HEADERS],
+            content=body,
+            request=request,
+        )
+        await asyncio.to_thread(self._cassette.append, request, response)
+        return response
+
+    async def aclose(self) -> None:
+        await self._inner.aclose()
+
+
+class ReplayTransport(httpx.AsyncBaseTransport):
+    """Serves responses from a cassette, matched on method + full URL.
+
+    Repeated requests consume recorded responses in order; once only one is
+    left it is served indefinitely so polling loops keep running.
+    """
+
+    def __init__(self, entries: list[dict]):
+        self._pending: dict[str, list[dict]] = {}
+        for entry in entries:
+            self._pending.setdefault(entry["request"], []).append(entry)
+
+    async def handle_async_request(self, request: httpx.Request) -> httpx.Response:
+        key = _request_key(request)
+        queue = self._pending.get(key)
+        if not queue:
+            raise FeedError(f"No recorded response for {key}")
+
+        entry = queue.pop(0) if len(queue) > 1 else queue[0]
+        return httpx.Response(
+            status_code=entry["status"],
+            headers=entry["headers"],
+            content=entry["body"].encode("utf-8"),
+            request=request,
+        )
+
+
+def _build_transport(http: HttpConfig) -> httpx.AsyncBaseTransport:
+    if http.replay_path:
+        if http.record_path:
+            logger.warning(
+                "Both TYR_RECORD and TYR_REPLAY are set - replaying from %s, not recording",
+                http.replay_path,
+            )
+        path = Path(http.replay_path)
+        logger.info("Replaying HTTP traffic from %s", path)
+        return ReplayTransport(Cassette(path).load())
+
+    # Limits must live on the transport: httpx ignores client-level limits
+    # once a custom transport is supplied
+    network = httpx.AsyncHTTPTransport(
+        limits=httpx.Limits(
+            max_connections=http.max_connections,
+            max_keepalive_connections=http.max_keepalive_connections,
+            keepalive_expiry=http.keepalive_expiry_secs,
+        ),
+        http2=http.http2,
+    )
+
+    if http.record_path:
+        path = Path(http.record_path)
+        if path not in _cassettes:
+            logger.info("Recording HTTP traffic to %s", path)
+            _cassettes[path] = Cassette.create(path)
+        return RecordingTransport(_cassettes[path], inner=network)
+
+    return network
+
+
+def create_client(base_url: str, http: Optional[HttpConfig] = None) -> httpx.AsyncClient:
+    """Build an AsyncClient for a REST feed, honouring record/replay settings.
+
+    Pool limits and keep-alive come from HttpConfig.
+    """
+    http = http or HttpConfig()
+    return httpx.AsyncClient(
+        base_url=base_url,
+        timeout=http.timeout_secs,
+        headers={"Accept": "application/json"},
+        transport=_build_transport(http),
+    )
+
+
+def _retry_after(resp: httpx.Response) -> Optional[float]:
+    value = resp.headers.get("retry-after")
+    try:
+        return float(value) if value is not None else None
+    except ValueError:
+        return None
+
+
+async def get_json(client: httpx.AsyncClient, path: str, params: Optional[dict] = None) -> dict:
+    """GET a JSON document, mapping failures onto typed FeedError subclasses."""
+    try:
+        resp = await client.get(path, params=params)
+    except httpx.HTTPError as exc:
+        raise ProviderUnavailableError(f"GET {path} failed: {exc}") from exc
+
+    if resp.status_code == 429:
+        raise RateLimitedError(f"GET {path} rate limited", retry_after=_retry_after(resp))
+    if resp.status_code >= 500:
+        raise ProviderUnavailableError(f"GET {path} returned HTTP {resp.status_code}")
+    if resp.status_code >= 400:
+        raise FeedError(f"GET {path} returned HTTP {resp.status_code}")
+
+    try:
+        data = resp.json()
+    except ValueError as exc:
+        raise InvalidResponseError(
+            f"GET {path} returned invalid JSON", raw=resp.text[:_RAW_PREVIEW_CHARS]
+        ) from exc
+
+    if not isinstance(data, dict):
+        raise InvalidResponseError(
+            f"GET {path} returned a JSON {type(data).__name__}, expected an object",
+            raw=resp.text[:_RAW_PREVIEW_CHARS],
+        )
+    return data
+
+
+async def get_json_retrying(
+    client: httpx.AsyncClient,
+    path: str,
+    params: Optional[dict] = None,
+    retry_delay: float = _RETRY_DELAY_SECS,
+) -> dict:
+    """get_json that waits out rate limits and transient upstream failures.
+
+    RateLimitedError (honouring Retry-After) and ProviderUnavailableError are
+    retried up to _MAX_RETRIES times and then re-raised; any other FeedError
+    propagates immediately.
+    """
+    attempt = 0
+    while True:
+        try:
+            return await get_json(client, path, params)
+        except (RateLimitedError, ProviderUnavailableError) as exc:
+            attempt += 1
+            if attempt > _MAX_RETRIES:
+                raise
+            retry_after = exc.retry_after if isinstance(exc, RateLimitedError) else None
+            delay = retry_after if retry_after is not None else retry_delay
+            logger.warning(
+                "%s - retrying in %.1fs (%d/%d)", exc, delay, attempt, _MAX_RETRIES
+            )
+            await asyncio.sleep(delay)
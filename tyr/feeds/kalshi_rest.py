@@ -10,11 +10,12 @@ import time
 from decimal import Decimal
 from typing import Optional
 
-import httpx
-
+from tyr.core.config import HttpConfig
+from tyr.core.exceptions import RateLimitedError
 from tyr.core.logging import get_logger
 from tyr.core.models import MarketInfo, Orderbook, Platform, PriceLevel
 from tyr.feeds.base import DataFeed
+from tyr.feeds.http import create_client, get_json, get_json_retrying
 
 logger = get_logger(__name__)
 
@@ -30,27 +31,28 @@ class KalshiRestFeed(DataFeed):
         queue: asyncio.Queue,
         tickers: Optional[list[str]] = None,
         poll_interval: float = 5.0,
+        http: Optional[HttpConfig] = None,
     ):
         super().__init__(queue)
         self.tickers = tickers or []
         self.poll_interval = poll_interval
-        self._client = httpx.AsyncClient(
-            base_url=KALSHI_BASE,
-            timeout=15.0,
-            headers={"Accept": "application/json"},
-        )
+        self._client = create_client(KALSHI_BASE, http)
 
     async def run(self) -> None:
         self._running = True
         logger.info("KalshiRestFeed starting (poll interval %.1fs)", self.poll_interval)
         while self._running:
+            delay = self.poll_interval
             try:
                 await self._poll_once()
             except asyncio.CancelledError:
                 break
+            except RateLimitedError as exc:
+                delay = max(delay, exc.retry_after or 0.0)
+                logger.warning("Kalshi rate limited - backing off %.1fs", delay)
             except Exception as exc:
                 logger.warning("Kalshi poll error: %s", exc)
-            await asyncio.sleep(self.poll_interval)
+            await asyncio.sleep(delay)
 
     async def stop(self) -> None:
         self._running = False
@@ -70,15 +72,20 @@ class KalshiRestFeed(DataFeed):
             params["tickers"] = ",".join(self.tickers)
 
         try:
-            resp = await self._client.get("/markets", params=params)
-            resp.raise_for_status()
-            return resp.json().get("markets", [])
+            data = await get_json(self._client, "/markets", params)
+            return data.get("markets", [])
+        except RateLimitedError:
+            raise
         except Exception as exc:
             logger.warning("Kalshi /markets fetch failed: %s", exc)
             return []
 
     async def get_all_markets(self) -> list[dict]:
-        """Fetch all open Kalshi markets (for bootstrapping)."""
+        """Fetch all open Kalshi markets (for bootstrapping).
+
+        Transient failures are retried on the same cursor; anything that
+        persists raises rather than returning a partial list.
+        """
         all_markets: list[dict] = []
         cursor: Optional[str] = None
 
@@ -87,18 +94,14 @@ class KalshiRestFeed(DataFeed):
             if cursor:
                 params["cursor"] = cursor
 
-            try:
-                resp = await self._client.get("/markets", params=params)
-                resp.raise_for_status()
-                data = resp.json()
-                page = data.get("markets", [])
-                all_markets.extend(page)
-                cursor = data.get("cursor")
-                logger.debug("Fetched %d Kalshi markets (total: %d)", len(page), len(all_markets))
-                if not cursor or not page:
-                    break
-            except Exception as exc:
-                logger.error("Kalshi bootstrap fetch failed: %s", exc)
+            data = await get_json_retrying(
+                self._client, "/markets", params, retry_delay=self.poll_interval
+            )
+            page = data.get("markets", [])
+            all_markets.extend(page)
+            cursor = data.get("cursor")
+            logger.debug("Fetched %d Kalshi markets (total: %d)", len(page), len(all_markets))
+            if not cursor or not page:
                 break
 
         logger.info("Fetched %d total Kalshi markets", len(all_markets))
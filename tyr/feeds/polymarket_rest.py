@@ -8,10 +8,10 @@ import time
 from decimal import Decimal
 from typing import Optional
 
-import httpx
-
+from tyr.core.config import HttpConfig
 from tyr.core.logging import get_logger
 from tyr.core.models import MarketInfo, Orderbook, Platform, PriceLevel
+from tyr.feeds.http import create_client, get_json, get_json_retrying
 
 logger = get_logger(__name__)
 
@@ -21,13 +21,9 @@ CLOB_BASE = "https://clob.polymarket.com"
 class PolymarketRestClient:
     """Thin async wrapper around the Polymarket CLOB REST API."""
 
-    def __init__(self, page_size: int = 500):
+    def __init__(self, page_size: int = 500, http: Optional[HttpConfig] = None):
         self.page_size = page_size
-        self._client = httpx.AsyncClient(
-            base_url=CLOB_BASE,
-            timeout=15.0,
-            headers={"Accept": "application/json"},
-        )
+        self._client = create_client(CLOB_BASE, http)
 
     async def close(self) -> None:
         await self._client.aclose()
@@ -37,16 +33,18 @@ class PolymarketRestClient:
         next_cursor: Optional[str] = None,
         active_only: bool = True,
     ) -> tuple[list[dict], Optional[str]]:
-        """Fetch one page of markets. Returns (markets, next_cursor)."""
+        """Fetch one page of markets. Returns (markets, next_cursor).
+
+        Rate limits and transient upstream failures are retried; a FeedError
+        subclass is raised if the request still fails.
+        """
         params: dict = {"limit": self.page_size}
         if next_cursor:
             params["next_cursor"] = next_cursor
         if active_only:
             params["active"] = "true"
 
-        resp = await self._client.get("/markets", params=params)
-        resp.raise_for_status()
-        data = resp.json()
+        data = await get_json_retrying(self._client, "/markets", params)
         return data.get("data", []), data.get("next_cursor")
 
     async def get_all_markets(self, active_only: bool = True) -> list[dict]:
@@ -67,9 +65,7 @@ class PolymarketRestClient:
     async def get_orderbook(self, token_id: str) -> Optional[Orderbook]:
         """Fetch current orderbook for a token (YES or NO side)."""
         try:
-            resp = await self._client.get("/book", params={"token_id": token_id})
-            resp.raise_for_status()
-            data = resp.json()
+            data = await get_json(self._client, "/book", {"token_id": token_id})
             return _parse_orderbook(data, token_id)
         except Exception as exc:
             logger.warning("Failed to fetch orderbook for %s: %s", token_id, exc)
@@ -78,9 +74,7 @@ class PolymarketRestClient:
     async def get_market(self, condition_id: str) -> Optional[dict]:
         """Fetch a single market by condition_id."""
         try:
-            resp = await self._client.get(f"/markets/{condition_id}")
-            resp.raise_for_status()
-            return resp.json()
+            return await get_json(self._client, f"/markets/{condition_id}")
         except Exception as exc:
             logger.warning("Failed to fetch market %s: %s", condition_id, exc)
             return None
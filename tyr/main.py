@@ -15,7 +15,7 @@ from tyr.core.logging import get_logger, setup_logging
 from tyr.core.models import Orderbook
 from tyr.feeds.feed_manager import FeedManager
 from tyr.storage.database import close_db, init_db
-from tyr.utils.console import print_startup_banner
+from tyr.utils.console import configure_console, print_startup_banner
 
 logger = get_logger(__name__)
 
@@ -84,9 +84,10 @@ async def run() -> None:
     config = get_config()
     setup_logging(config.log_level, config.log_file)
 
+    configure_console(no_color=config.console_no_color, ascii_only=config.console_ascii)
     print_startup_banner(dry_run=config.thresholds.dry_run)
 
-    await init_db(config.db_path)
+    await init_db(config.db_path, config.db_url)
 
     poly_token_ids = _load_token_ids()
     kalshi_tickers = _load_kalshi_tickers()
@@ -1,19 +1,52 @@
 """Rich-based live console display for the bot."""
 from __future__ import annotations
 
+import os
+import sys
 from datetime import datetime
 from decimal import Decimal
+from pathlib import Path
 
 from rich.console import Console
 from rich.table import Table
 from rich import box
 
+# Glyph sets: plain ASCII keeps CI logs and legacy code pages readable
+_UNICODE_GLYPHS = {"dash": "—", "pair": "↔", "arrow": "→", "box": box.SIMPLE}
+_ASCII_GLYPHS = {"dash": "-", "pair": "<->", "arrow": "->", "box": box.ASCII}
+
 console = Console()
+_glyphs = _UNICODE_GLYPHS
+
+
+def _supports_unicode_glyphs() -> bool:
+    encoding = getattr(sys.stdout, "encoding", None) or "ascii"
+    sample = _UNICODE_GLYPHS["dash"] + _UNICODE_GLYPHS["pair"] + _UNICODE_GLYPHS["arrow"] + "─"
+    try:
+        sample.encode(encoding)
+    except (UnicodeEncodeError, LookupError):
+        return False
+    return True
+
+
+def configure_console(no_color: bool = False, ascii_only: bool = False) -> None:
+    """Rebuild the shared console for the current terminal.
+
+    ASCII glyphs are used when requested or when stdout cannot encode the
+    Unicode set (e.g. output piped through a legacy Windows code page). A
+    non-empty NO_COLOR environment variable disables colour regardless of
+    `no_color` (https://no-color.org).
+    """
+    global console, _glyphs
+    ascii_only = ascii_only or not _supports_unicode_glyphs()
+    no_color = no_color or bool(os.environ.get("NO_COLOR"))
+    console = Console(no_color=no_color, emoji=not ascii_only, safe_box=True)
+    _glyphs = _ASCII_GLYPHS if ascii_only else _UNICODE_GLYPHS
 
 
 def print_startup_banner(dry_run: bool) -> None:
     mode = "[bold yellow]DRY RUN[/bold yellow]" if dry_run else "[bold red]LIVE[/bold red]"
-    console.print(f"\n[bold cyan]Tyr Arbitrage Bot[/bold cyan] — Mode: {mode}\n")
+    console.print(f"\n[bold cyan]Tyr Arbitrage Bot[/bold cyan] {_glyphs['dash']} Mode: {mode}\n")
 
 
 def print_opportunity(opp_id: str, arb_type: str, net_profit_pct: Decimal,
@@ -24,16 +57,24 @@ def print_opportunity(opp_id: str, arb_type: str, net_profit_pct: Decimal,
         f"[dim]{datetime.now().strftime('%H:%M:%S')}[/dim] "
         f"[cyan]{arb_type.upper()}[/cyan] "
         f"[{color}]+{float(net_profit_pct)*100:.2f}%  ${float(net_profit):.4f}[/{color}]  "
-        f"{leg_a_label} ↔ {leg_b_label}  "
+        f"{leg_a_label} {_glyphs['pair']} {leg_b_label}  "
         f"[dim]{status}[/dim]"
     )
 
 
 def print_stats(detected: int, dry_run: int, filled: int, failed: int) -> None:
-    table = Table(box=box.SIMPLE, show_header=True, header_style="bold")
+    table = Table(box=_glyphs["box"], show_header=True, header_style="bold")
     table.add_column("Detected")
     table.add_column("Dry Run")
     table.add_column("Filled")
     table.add_column("Failed")
     table.add_row(str(detected), str(dry_run), str(filled), str(failed))
     console.print(table)
+
+
+def print_bootstrap_summary(poly_count: int, poly_path: Path,
+                            kalshi_count: int, kalshi_path: Path) -> None:
+    arrow = _glyphs["arrow"]
+    console.print("\nBootstrap complete:", markup=False)
+    console.print(f"  Polymarket: {poly_count} markets {arrow} {poly_path}", markup=False)
+    console.print(f"  Kalshi:     {kalshi_count} markets {arrow} {kalshi_path}", markup=False)